@@ -129,3 +129,7 @@ pub static GLOBAL_BUCKET: LazyLock<Option<String>> =
 /// Whether to enforce a global bucket for file data (default: false)
 pub static GLOBAL_BUCKET_ENFORCED: LazyLock<bool> =
 	lazy_env_parse!("SURREAL_GLOBAL_BUCKET_ENFORCED", bool, false);
+
+/// The time after which a model file which no model definition refers to can be deleted (default: 1 hour)
+pub static ML_OBJECT_GC_AGE: LazyLock<u64> =
+	lazy_env_parse!("SURREAL_ML_OBJECT_GC_AGE", u64, 60 * 60);
@@ -807,6 +807,20 @@ pub enum Error {
 	#[error("Object Store error: {0}")]
 	ObsError(#[from] ObjectStoreError),
 
+	/// The object store configuration is invalid
+	#[error("The object store configured with '{name}' is invalid: {message}")]
+	ObsInvalidConfig {
+		name: String,
+		message: String,
+	},
+
+	/// The configured object store could not be reached
+	#[error("The object store configured with '{name}' could not be reached: {message}")]
+	ObsUnreachable {
+		name: String,
+		message: String,
+	},
+
 	/// There was an error with model computation
 	#[error("There was an error with model computation: {0}")]
 	ModelComputation(String),
@@ -22,6 +22,8 @@ pub enum Category {
 	AccessGrant,
 	/// crate::key::root::nd                 /!nd{nd}
 	Node,
+	/// crate::key::root::ml                 /!ml{hash}
+	ModelReference,
 	/// crate::key::root::ni                 /!ni
 	NamespaceIdentifier,
 	/// crate::key::root::ns                 /!ns{ns}
@@ -186,6 +188,7 @@ impl Display for Category {
 			Self::AccessRoot => "AccessRoot",
 			Self::AccessGrant => "AccessGrant",
 			Self::Node => "Node",
+			Self::ModelReference => "ModelReference",
 			Self::NamespaceIdentifier => "NamespaceIdentifier",
 			Self::Namespace => "Namespace",
 			Self::User => "User",
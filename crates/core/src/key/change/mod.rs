@@ -54,7 +54,6 @@ pub fn prefix_ts(ns: &str, db: &str, vs: VersionStamp) -> Result<Vec<u8>, Error>
 }
 
 /// Returns the prefix for the whole database change feeds
-#[expect(unused)]
 pub fn prefix(ns: &str, db: &str) -> Result<Vec<u8>, Error> {
 	let mut k = crate::key::database::all::new(ns, db).encode()?;
 	k.extend_from_slice(b"#");
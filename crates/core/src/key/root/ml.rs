@@ -0,0 +1,53 @@
+//! Stores the number of model definitions which refer to a model file
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Ml<'a> {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub hash: &'a str,
+}
+impl_key!(Ml<'a>);
+
+pub fn new(hash: &str) -> Ml<'_> {
+	Ml::new(hash)
+}
+
+impl Categorise for Ml<'_> {
+	fn categorise(&self) -> Category {
+		Category::ModelReference
+	}
+}
+
+impl<'a> Ml<'a> {
+	pub fn new(hash: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'!',
+			_b: b'm',
+			_c: b'l',
+			hash,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		let val = Ml::new("testhash");
+		let enc = Ml::encode(&val).unwrap();
+		assert_eq!(enc, b"/!mltesthash\0");
+
+		let dec = Ml::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -1,6 +1,7 @@
 pub mod ac;
 pub mod access;
 pub mod all;
+pub mod ml;
 pub mod nd;
 pub mod ni;
 pub mod ns;
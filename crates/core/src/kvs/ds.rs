@@ -717,6 +717,36 @@ impl Datastore {
 		Ok(())
 	}
 
+	/// Run the background task to perform model file garbage collection
+	///
+	/// A model file is deleted once no model definition refers to it, and once
+	/// it has not been written for the configured period, so that a model file
+	/// which is being imported is not deleted before its definition is committed.
+	///
+	/// Any model file which this datastore does not refer to is deleted, so this
+	/// must only be run when the object store is not shared with another datastore.
+	#[cfg(feature = "ml")]
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
+	pub async fn ml_process(&self) -> Result<(), Error> {
+		// Output function invocation details to logs
+		trace!(target: TARGET, "Running model file garbage collection");
+		// Only delete model files which have not been written recently
+		let age = chrono::Duration::seconds(*crate::cnf::ML_OBJECT_GC_AGE as i64);
+		let before = chrono::Utc::now() - age;
+		// Delete the model files which are no longer referenced
+		for hash in crate::obs::list_models(before).await? {
+			let txn = self.transaction(Read, Optimistic).await?;
+			let key = crate::key::root::ml::new(&hash);
+			let referenced = catch!(txn, txn.exists(key, None).await);
+			txn.cancel().await?;
+			if !referenced {
+				crate::obs::del_model(&hash, before).await?;
+			}
+		}
+		// Everything ok
+		Ok(())
+	}
+
 	/// Run the datastore shutdown tasks, perfoming any necessary cleanup
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
 	pub async fn shutdown(&self) -> Result<(), Error> {
@@ -14,6 +14,7 @@ use crate::key::database::sq::Sq;
 use crate::kvs::cache;
 use crate::kvs::cache::tx::TransactionCache;
 use crate::kvs::scanner::Scanner;
+use crate::kvs::KeyDecode;
 use crate::kvs::Transactor;
 use crate::sql::statements::define::BucketDefinition;
 use crate::sql::statements::define::DefineConfigStatement;
@@ -35,6 +36,7 @@ use crate::sql::statements::LiveStatement;
 use crate::sql::Id;
 use crate::sql::Permissions;
 use crate::sql::Value;
+use crate::vs::VersionStamp;
 use futures::lock::Mutex;
 use futures::lock::MutexGuard;
 use futures::stream::Stream;
@@ -1319,6 +1321,61 @@ impl Transaction {
 		}
 	}
 
+	/// Change the number of model definitions which refer to the model file with this hash.
+	///
+	/// The reference count is removed once no model definition refers to the model file,
+	/// after which the model file can be garbage collected.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn add_model_ref(&self, hash: &str, delta: i64) -> Result<(), Error> {
+		// Definitions which were not imported do not refer to a model file
+		if hash.is_empty() {
+			return Ok(());
+		}
+		let key = crate::key::root::ml::new(hash).encode()?;
+		let count = match self.get(key.clone(), None).await? {
+			Some(val) => revision::from_slice::<u64>(&val)?,
+			None => 0,
+		};
+		match count.saturating_add_signed(delta) {
+			0 => self.del(key).await,
+			n => self.set(key, revision::to_vec(&n)?, None).await,
+		}
+	}
+
+	/// Retrieve the time, in seconds, at which the change feed of a database last changed.
+	///
+	/// This is the first timestamp recorded for the change feed after its latest change. No
+	/// time is returned if the change feed holds no changes, or if no timestamp has been
+	/// recorded since its latest change.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_changefeed_modified(&self, ns: &str, db: &str) -> Result<Option<u64>, Error> {
+		// Find the versionstamp of the latest change
+		let beg = crate::key::change::prefix(ns, db)?;
+		let end = crate::key::change::suffix(ns, db)?;
+		let Some(key) = self.keysr(beg..end, 1, None).await?.pop() else {
+			return Ok(None);
+		};
+		let latest = crate::key::change::Cf::decode(&key)?.vs;
+		// Find the first timestamp recorded after the latest change
+		let beg = crate::key::database::ts::prefix(ns, db)?;
+		let mut end = crate::key::database::ts::suffix(ns, db)?;
+		let mut found = None;
+		loop {
+			let res = self.scanr(beg.clone()..end.clone(), *NORMAL_FETCH_SIZE, None).await?;
+			let more = res.len() >= *NORMAL_FETCH_SIZE as usize;
+			for (k, v) in res {
+				if VersionStamp::from_slice(&v)? <= latest {
+					return Ok(found);
+				}
+				found = Some(crate::key::database::ts::Ts::decode(&k)?.ts);
+				end = k;
+			}
+			if !more {
+				return Ok(found);
+			}
+		}
+	}
+
 	/// Retrieve a specific api definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_db_api(
@@ -4,7 +4,9 @@
 //! crate. This will enable the user to store objects using local file storage, memory, or cloud storage such as S3 or GCS.
 use crate::err::Error;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use futures::stream::BoxStream;
+use futures::StreamExt;
 #[cfg(not(target_family = "wasm"))]
 use object_store::local::LocalFileSystem;
 #[cfg(target_family = "wasm")]
@@ -12,33 +14,38 @@ use object_store::memory::InMemory;
 use object_store::parse_url;
 use object_store::path::Path;
 use object_store::ObjectStore;
-use sha1::{Digest, Sha1};
+use sha2::{Digest, Sha256};
 use std::env;
 use std::fs;
 use std::sync::Arc;
 use std::sync::LazyLock;
 use url::Url;
 
-fn initialize_store(env_var: &str, default_dir: &str) -> Arc<dyn ObjectStore> {
+/// Builds the object store configured by the given environment variable.
+///
+/// The variable accepts any URL understood by [`parse_url`], such as `file:///data`,
+/// `memory:///`, or `s3://bucket/prefix`. When it is not set, a local directory is used.
+fn try_initialize_store(env_var: &str, default_dir: &str) -> Result<Arc<dyn ObjectStore>, Error> {
+	let invalid = |message: String| Error::ObsInvalidConfig {
+		name: env_var.to_owned(),
+		message,
+	};
 	match std::env::var(env_var) {
 		Ok(url) => {
-			let url =
-				Url::parse(&url).unwrap_or_else(|_| panic!("Expected a valid url for {}", env_var));
-			let (store, _) =
-				parse_url(&url).unwrap_or_else(|_| panic!("Expected a valid url for {}", env_var));
+			let url = Url::parse(&url).map_err(|e| invalid(e.to_string()))?;
+			let (store, _) = parse_url(&url).map_err(|e| invalid(e.to_string()))?;
 			if url.scheme() == "file" {
-				let path_buf = url.to_file_path().unwrap();
-				let path = path_buf.to_str().unwrap();
+				let path_buf =
+					url.to_file_path().map_err(|_| invalid(format!("{url} is not a file path")))?;
 				if !path_buf.as_path().exists() {
-					fs::create_dir_all(path_buf.as_path())
-						.unwrap_or_else(|_| panic!("Failed to create directory {:?}", path));
+					fs::create_dir_all(path_buf.as_path()).map_err(|e| invalid(e.to_string()))?;
 				}
-				Arc::new(
-					LocalFileSystem::new_with_prefix(path)
-						.expect("Failed to create LocalFileSystem"),
-				)
+				Ok(Arc::new(
+					LocalFileSystem::new_with_prefix(path_buf)
+						.map_err(|e| invalid(e.to_string()))?,
+				))
 			} else {
-				Arc::new(store)
+				Ok(Arc::new(store))
 			}
 		}
 		Err(_) => {
@@ -46,24 +53,56 @@ fn initialize_store(env_var: &str, default_dir: &str) -> Arc<dyn ObjectStore> {
 				"No {} environment variable found, using default directory {}",
 				env_var, default_dir
 			);
-			let path = env::current_dir().unwrap().join(default_dir);
+			let path = env::current_dir()?.join(default_dir);
 			if !path.exists() || !path.is_dir() {
-				fs::create_dir_all(&path)
-					.unwrap_or_else(|_| panic!("Failed to create directory {:?}", path));
+				fs::create_dir_all(&path)?;
 			}
 			#[cfg(not(target_family = "wasm"))]
 			{
-				// As long as the provided path is correct, the following should never panic
-				Arc::new(LocalFileSystem::new_with_prefix(path).unwrap())
+				Ok(Arc::new(
+					LocalFileSystem::new_with_prefix(path).map_err(|e| invalid(e.to_string()))?,
+				))
 			}
 			#[cfg(target_family = "wasm")]
 			{
-				Arc::new(InMemory::new())
+				Ok(Arc::new(InMemory::new()))
 			}
 		}
 	}
 }
 
+fn initialize_store(env_var: &str, default_dir: &str) -> Arc<dyn ObjectStore> {
+	try_initialize_store(env_var, default_dir).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Checks that an object store can be reached by requesting a file which need not exist.
+async fn check_store(env_var: &str, store: &dyn ObjectStore) -> Result<(), Error> {
+	match store.head(&Path::from(".surrealdb-probe")).await {
+		Ok(_)
+		| Err(object_store::Error::NotFound {
+			..
+		}) => Ok(()),
+		Err(e) => Err(Error::ObsUnreachable {
+			name: env_var.to_owned(),
+			message: e.to_string(),
+		}),
+	}
+}
+
+/// Validates and initialises the configured object stores.
+///
+/// This should be called at startup, so that a misconfigured or unreachable
+/// object store is reported before any file is read from or written to it.
+pub async fn init() -> Result<(), Error> {
+	// Validate the configuration before the stores are lazily created
+	try_initialize_store("SURREAL_OBJECT_STORE", "store")?;
+	try_initialize_store("SURREAL_CACHE_STORE", "cache")?;
+	// Ensure that both of the stores can be reached
+	check_store("SURREAL_OBJECT_STORE", STORE.as_ref()).await?;
+	check_store("SURREAL_CACHE_STORE", CACHE.as_ref()).await?;
+	Ok(())
+}
+
 static STORE: LazyLock<Arc<dyn ObjectStore>> =
 	LazyLock::new(|| initialize_store("SURREAL_OBJECT_STORE", "store"));
 
@@ -104,13 +143,119 @@ pub async fn del(file: &str) -> Result<(), Error> {
 }
 
 /// Hashes the bytes of a file to a string for the storage of a file.
+///
+/// Model files are stored by this hash alone, across every namespace and database,
+/// so a collision resistant digest is used.
 pub fn hash(data: &[u8]) -> String {
-	let mut hasher = Sha1::new();
+	let mut hasher = Sha256::new();
 	hasher.update(data);
 	let result = hasher.finalize();
-	let mut output = hex::encode(result);
-	output.truncate(6);
-	output
+	hex::encode(result)
+}
+
+/// The directory in which model files are stored by their hash.
+const MODEL_OBJECTS: &str = "ml/objects";
+
+/// Returns the content-addressed path of the model file with the given hash.
+pub fn model_path(hash: &str) -> String {
+	format!("{MODEL_OBJECTS}/{hash}.surml")
+}
+
+/// Returns the paths at which a model file was stored before model files were content-addressed.
+///
+/// The server stored each model file under its namespace, database, name and version, while
+/// the embedded engine stored each model file under its hash alone.
+fn legacy_model_paths(ns: &str, db: &str, name: &str, version: &str, hash: &str) -> [String; 2] {
+	[format!("ml/{ns}/{db}/{name}-{version}-{hash}.surml"), hash.to_owned()]
+}
+
+/// Puts a model file into storage at its content-addressed path.
+///
+/// A file with the same contents is written again rather than skipped, so that its
+/// modification time is refreshed and it is not garbage collected before the definition
+/// which refers to it has been committed.
+pub async fn put_model(hash: &str, data: Vec<u8>) -> Result<(), Error> {
+	put(&model_path(hash), data).await
+}
+
+/// Gets a model file from storage, falling back to the paths used before model files were content-addressed.
+pub async fn get_model(
+	ns: &str,
+	db: &str,
+	name: &str,
+	version: &str,
+	hash: &str,
+) -> Result<Vec<u8>, Error> {
+	let mut res = get(&model_path(hash)).await;
+	for path in legacy_model_paths(ns, db, name, version, hash) {
+		match res {
+			Err(Error::ObsError(object_store::Error::NotFound {
+				..
+			})) => res = get(&path).await,
+			res => return res,
+		}
+	}
+	res
+}
+
+/// Streams a model file from storage, falling back to the paths used before model files were content-addressed.
+pub async fn stream_model(
+	ns: &str,
+	db: &str,
+	name: &str,
+	version: &str,
+	hash: &str,
+) -> Result<BoxStream<'static, Result<Bytes, object_store::Error>>, Error> {
+	let mut res = stream(model_path(hash)).await;
+	for path in legacy_model_paths(ns, db, name, version, hash) {
+		match res {
+			Err(Error::ObsError(object_store::Error::NotFound {
+				..
+			})) => res = stream(path).await,
+			res => return res,
+		}
+	}
+	res
+}
+
+/// Lists the hashes of the content-addressed model files which were last written before the given time.
+pub async fn list_models(before: DateTime<Utc>) -> Result<Vec<String>, Error> {
+	let mut hashes = Vec::new();
+	let mut list = STORE.list(Some(&Path::from(MODEL_OBJECTS)));
+	while let Some(meta) = list.next().await {
+		let meta = meta?;
+		if meta.last_modified < before {
+			if let Some(hash) = meta.location.filename().and_then(|v| v.strip_suffix(".surml")) {
+				hashes.push(hash.to_owned());
+			}
+		}
+	}
+	Ok(hashes)
+}
+
+/// Deletes a content-addressed model file from storage and from the cache.
+///
+/// The file is only deleted if it has not been written since the given time.
+pub async fn del_model(hash: &str, before: DateTime<Utc>) -> Result<(), Error> {
+	let path = Path::from(model_path(hash));
+	match STORE.head(&path).await {
+		Ok(meta) if meta.last_modified < before => (),
+		Ok(_)
+		| Err(object_store::Error::NotFound {
+			..
+		}) => return Ok(()),
+		Err(e) => return Err(e.into()),
+	}
+	for store in [&*STORE, &*CACHE] {
+		match store.delete(&path).await {
+			Ok(_)
+			| Err(object_store::Error::NotFound {
+				..
+			}) => (),
+			Err(e) => return Err(e.into()),
+		}
+	}
+	Ok(())
 }
 
 #[cfg(test)]
@@ -134,4 +279,40 @@ mod tests {
 		// Remove the dir
 		fs::remove_dir_all(current_dir.join("store")).unwrap();
 	}
+
+	async fn roundtrip(store: &dyn ObjectStore) {
+		let path = Path::from("ml/test/test/model-1.0.0-abcdef.surml");
+		let data = b"surrealdb".to_vec();
+		store.put(&path, Bytes::from(data.clone()).into()).await.unwrap();
+		let res = store.get(&path).await.unwrap().bytes().await.unwrap();
+		assert_eq!(res.to_vec(), data);
+		store.delete(&path).await.unwrap();
+		assert!(store.get(&path).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn test_initialize_store_local() {
+		let path = env::temp_dir().join(format!("surrealdb-test-store-{}", ulid::Ulid::new()));
+		let url = Url::from_directory_path(&path).unwrap();
+		env::set_var("SURREAL_TEST_LOCAL_STORE", url.as_str());
+		let store = try_initialize_store("SURREAL_TEST_LOCAL_STORE", "store").unwrap();
+		check_store("SURREAL_TEST_LOCAL_STORE", store.as_ref()).await.unwrap();
+		roundtrip(store.as_ref()).await;
+		fs::remove_dir_all(path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_initialize_store_memory() {
+		env::set_var("SURREAL_TEST_MEMORY_STORE", "memory:///");
+		let store = try_initialize_store("SURREAL_TEST_MEMORY_STORE", "store").unwrap();
+		check_store("SURREAL_TEST_MEMORY_STORE", store.as_ref()).await.unwrap();
+		roundtrip(store.as_ref()).await;
+	}
+
+	#[test]
+	fn test_initialize_store_invalid() {
+		env::set_var("SURREAL_TEST_INVALID_STORE", "unknown://bucket/path");
+		let res = try_initialize_store("SURREAL_TEST_INVALID_STORE", "store");
+		assert!(matches!(res, Err(Error::ObsInvalidConfig { .. })), "{:?}", res.err());
+	}
 }
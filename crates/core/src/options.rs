@@ -10,6 +10,7 @@ pub struct EngineOptions {
 	pub node_membership_check_interval: Duration,
 	pub node_membership_cleanup_interval: Duration,
 	pub changefeed_gc_interval: Duration,
+	pub ml_gc_interval: Option<Duration>,
 }
 
 impl Default for EngineOptions {
@@ -19,6 +20,7 @@ impl Default for EngineOptions {
 			node_membership_check_interval: Duration::from_secs(15),
 			node_membership_cleanup_interval: Duration::from_secs(300),
 			changefeed_gc_interval: Duration::from_secs(10),
+			ml_gc_interval: None,
 		}
 	}
 }
@@ -40,4 +42,10 @@ impl EngineOptions {
 		self.changefeed_gc_interval = interval;
 		self
 	}
+	/// Enables model file garbage collection, which must only be enabled when
+	/// the object store is not shared with any other datastore.
+	pub fn with_ml_gc_interval(mut self, interval: Option<Duration>) -> Self {
+		self.ml_gc_interval = interval;
+		self
+	}
 }
@@ -29,10 +29,38 @@ use surrealml::ndarray as mlNdarray;
 use surrealml::storage::surml_file::SurMlFile;
 
 #[cfg(feature = "ml")]
-const ARGUMENTS: &str = "The model expects 1 argument. The argument can be either a number, an object, or an array of numbers.";
+const ARGUMENTS: &str = "The model expects 1 argument. The argument can be either a number, an object, or an array of numbers. An array of objects or of arrays of numbers computes many rows at once.";
 
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Model";
 
+/// A single row of input for a batched model computation
+#[cfg(feature = "ml")]
+enum Row {
+	/// A row of named inputs, computed with buffered compute
+	Buffered(HashMap<String, f32>),
+	/// A row of ordered inputs, computed with raw compute
+	Raw(Vec<f32>),
+}
+
+/// Stacks raw rows of equal length into a single two-dimensional tensor, one row per input
+///
+/// No tensor is returned if any row is a buffered row, or if the rows differ in length.
+#[cfg(feature = "ml")]
+fn stack(rows: &[Row]) -> Option<mlNdarray::ArrayD<f32>> {
+	let size = match rows.first()? {
+		Row::Raw(v) => v.len(),
+		Row::Buffered(_) => return None,
+	};
+	let mut data = Vec::with_capacity(rows.len() * size);
+	for row in rows {
+		match row {
+			Row::Raw(v) if v.len() == size => data.extend_from_slice(v),
+			_ => return None,
+		}
+	}
+	mlNdarray::Array2::from_shape_vec((rows.len(), size), data).ok().map(|v| v.into_dyn())
+}
+
 #[revisioned(revision = 1)]
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[serde(rename = "$surrealdb::private::sql::Model")]
@@ -77,9 +105,6 @@ impl Model {
 		// Get the model definition
 		let (ns, db) = opt.ns_db()?;
 		let val = ctx.tx().get_db_model(ns, db, &self.name, &self.version).await?;
-		// Calculate the model path
-		let (ns, db) = opt.ns_db()?;
-		let path = format!("ml/{}/{}/{}-{}-{}.surml", ns, db, self.name, self.version, val.hash);
 		// Check permissions
 		if opt.check_perms(Action::View)? {
 			match &val.permissions {
@@ -130,7 +155,8 @@ impl Model {
 						message: ARGUMENTS.into(),
 					})?;
 				// Get the model file as bytes
-				let bytes = crate::obs::get(&path).await?;
+				let bytes =
+					crate::obs::get_model(ns, db, &self.name, &self.version, &val.hash).await?;
 				// Run the compute in a blocking task
 				let outcome: Vec<f32> = tokio::task::spawn_blocking(move || {
 					let mut file = SurMlFile::from_bytes(bytes).map_err(|err: SurrealError| {
@@ -157,7 +183,8 @@ impl Model {
 					message: ARGUMENTS.into(),
 				})?;
 				// Get the model file as bytes
-				let bytes = crate::obs::get(&path).await?;
+				let bytes =
+					crate::obs::get_model(ns, db, &self.name, &self.version, &val.hash).await?;
 				// Convert the argument to a tensor
 				let tensor = mlNdarray::arr1::<f32>(&[args]).into_dyn();
 				// Run the compute in a blocking task
@@ -178,6 +205,77 @@ impl Model {
 				// Convert the output to a value
 				Ok(outcome.into())
 			}
+			// Perform batched compute
+			Value::Array(v) if !v.is_empty() && v.iter().all(|x| x.is_object() || x.is_array()) => {
+				// Compute the model function arguments for every row
+				let rows = v
+					.into_iter()
+					.map(|x| match x {
+						Value::Object(v) => v
+							.into_iter()
+							.map(|(k, v)| Ok((k, v.coerce_to::<f64>()? as f32)))
+							.collect::<Result<HashMap<String, f32>, Error>>()
+							.map(Row::Buffered),
+						v => v
+							.coerce_to::<Vec<f64>>()
+							.map(|v| v.into_iter().map(|x| x as f32).collect())
+							.map(Row::Raw)
+							.map_err(Error::from),
+					})
+					.collect::<Result<Vec<Row>, Error>>()
+					.map_err(|_| Error::InvalidArguments {
+						name: format!("ml::{}<{}>", self.name, self.version),
+						message: ARGUMENTS.into(),
+					})?;
+				// Get the model file as bytes
+				let bytes =
+					crate::obs::get_model(ns, db, &self.name, &self.version, &val.hash).await?;
+				// Stack the rows into a single tensor, if they are all raw rows
+				let tensor = stack(&rows);
+				// Run the compute for every row in a single blocking task
+				let outcome: Vec<Vec<f32>> = tokio::task::spawn_blocking(move || {
+					let mut file = SurMlFile::from_bytes(bytes).map_err(|err: SurrealError| {
+						Error::ModelComputation(err.message.to_string())
+					})?;
+					// A model file does not declare whether it accepts a batch dimension, so
+					// the stacked rows are only used if the model accepts them, and returns
+					// an equal number of outputs for every row
+					if let Some(tensor) = tensor {
+						let compute_unit = ModelComputation {
+							surml_file: &mut file,
+						};
+						if let Ok(outcome) = compute_unit.raw_compute(tensor, None) {
+							if !outcome.is_empty() && outcome.len() % rows.len() == 0 {
+								let size = outcome.len() / rows.len();
+								return Ok(outcome.chunks(size).map(<[f32]>::to_vec).collect());
+							}
+						}
+					}
+					// Otherwise run the compute for each row in turn
+					rows.into_iter()
+						.map(|row| {
+							let compute_unit = ModelComputation {
+								surml_file: &mut file,
+							};
+							match row {
+								Row::Buffered(mut args) => compute_unit.buffered_compute(&mut args),
+								Row::Raw(args) => {
+									let tensor = mlNdarray::arr1::<f32>(&args).into_dyn();
+									compute_unit.raw_compute(tensor, None)
+								}
+							}
+							.map_err(|err: SurrealError| {
+								Error::ModelComputation(err.message.to_string())
+							})
+						})
+						.collect::<Result<Vec<Vec<f32>>, Error>>()
+				})
+				.await
+				.unwrap()
+				.map_err(ControlFlow::from)?;
+				// Convert the output to a value
+				Ok(outcome.into())
+			}
 			// Perform raw compute
 			Value::Array(v) => {
 				// Compute the model function arguments
@@ -191,7 +289,8 @@ impl Model {
 					})
 					.map_err(ControlFlow::from)?;
 				// Get the model file as bytes
-				let bytes = crate::obs::get(&path).await?;
+				let bytes =
+					crate::obs::get_model(ns, db, &self.name, &self.version, &val.hash).await?;
 				// Convert the argument to a tensor
 				let tensor = mlNdarray::arr1::<f32>(&args).into_dyn();
 				// Run the compute in a blocking task
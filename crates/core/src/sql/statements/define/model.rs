@@ -41,7 +41,8 @@ impl DefineModelStatement {
 		let txn = ctx.tx();
 		// Check if the definition exists
 		let (ns, db) = opt.ns_db()?;
-		if txn.get_db_model(ns, db, &self.name, &self.version).await.is_ok() {
+		let existing = txn.get_db_model(ns, db, &self.name, &self.version).await.ok();
+		if existing.is_some() {
 			if self.if_not_exists {
 				return Ok(Value::None);
 			} else if !self.overwrite {
@@ -65,6 +66,15 @@ impl DefineModelStatement {
 			None,
 		)
 		.await?;
+		// Update the references to the model files
+		match existing {
+			Some(ml) if ml.hash == self.hash => (),
+			Some(ml) => {
+				txn.add_model_ref(&ml.hash, -1).await?;
+				txn.add_model_ref(&self.hash, 1).await?;
+			}
+			None => txn.add_model_ref(&self.hash, 1).await?,
+		}
 		// Clear the cache
 		txn.clear();
 		// Ok all good
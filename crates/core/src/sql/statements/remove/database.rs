@@ -42,6 +42,10 @@ impl RemoveDatabaseStatement {
 			}
 			// Get the definition
 			let db = txn.get_db(ns, &self.name).await?;
+			// Release the references to the model files
+			for ml in txn.all_db_models(ns, &db.name).await?.iter() {
+				txn.add_model_ref(&ml.hash, -1).await?;
+			}
 			// Delete the definition
 			let key = crate::key::namespace::db::new(ns, &db.name);
 			match self.expunge {
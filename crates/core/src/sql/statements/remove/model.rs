@@ -33,9 +33,10 @@ impl RemoveModelStatement {
 			// Delete the definition
 			let key = crate::key::database::ml::new(ns, db, &ml.name, &ml.version);
 			txn.del(key).await?;
+			// Release the reference to the model file
+			txn.add_model_ref(&ml.hash, -1).await?;
 			// Clear the cache
 			txn.clear();
-			// TODO Remove the model file from storage
 			// Ok all good
 			Ok(Value::None)
 		}
@@ -41,6 +41,12 @@ impl RemoveNamespaceStatement {
 			}
 			// Get the definition
 			let ns = txn.get_ns(&self.name).await?;
+			// Release the references to the model files
+			for db in txn.all_db(&ns.name).await?.iter() {
+				for ml in txn.all_db_models(&ns.name, &db.name).await?.iter() {
+					txn.add_model_ref(&ml.hash, -1).await?;
+				}
+			}
 			// Delete the definition
 			let key = crate::key::root::ns::new(&ns.name);
 			match self.expunge {
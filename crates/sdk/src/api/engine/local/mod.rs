@@ -472,7 +472,7 @@ async fn export_ml(
 	// Attempt to get the model definition
 	let info = tx.get_db_model(&nsv, &dbv, &name, &version).await?;
 	// Export the file data in to the store
-	let mut data = crate::obs::stream(info.hash.clone()).await?;
+	let mut data = crate::obs::stream_model(&nsv, &dbv, &name, &version, &info.hash).await?;
 	// Process all stream values
 	while let Some(Ok(bytes)) = data.next().await {
 		if chn.send(bytes.to_vec()).await.is_err() {
@@ -1033,8 +1033,8 @@ async fn router(
 			let data = file.to_bytes();
 			// Calculate the hash of the model file
 			let hash = crate::obs::hash(&data);
-			// Insert the file data in to the store
-			crate::obs::put(&hash, data).await?;
+			// Insert the file data in to the store, refreshing it if already stored
+			crate::obs::put_model(&hash, data).await?;
 			// Insert the model in to the database
 			let mut model = DefineModelStatement::default();
 			model.name = file.header.name.to_string().into();
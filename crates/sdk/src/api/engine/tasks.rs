@@ -47,7 +47,13 @@ pub fn init(dbs: Arc<Datastore>, canceller: CancellationToken, opts: &EngineOpti
 	let task2 = spawn_task_node_membership_check(dbs.clone(), canceller.clone(), opts);
 	let task3 = spawn_task_node_membership_cleanup(dbs.clone(), canceller.clone(), opts);
 	let task4 = spawn_task_changefeed_cleanup(dbs.clone(), canceller.clone(), opts);
-	Tasks(vec![task1, task2, task3, task4])
+	#[cfg_attr(not(feature = "ml"), expect(unused_mut))]
+	let mut tasks = vec![task1, task2, task3, task4];
+	#[cfg(feature = "ml")]
+	if let Some(delay) = opts.ml_gc_interval {
+		tasks.push(spawn_task_ml_cleanup(dbs.clone(), canceller.clone(), delay));
+	}
+	Tasks(tasks)
 }
 
 fn spawn_task_node_membership_refresh(
@@ -174,6 +180,36 @@ fn spawn_task_changefeed_cleanup(
 	}))
 }
 
+#[cfg(feature = "ml")]
+fn spawn_task_ml_cleanup(
+	dbs: Arc<Datastore>,
+	canceller: CancellationToken,
+	delay: Duration,
+) -> Task {
+	// Spawn a future
+	Box::pin(spawn(async move {
+		// Log the interval frequency
+		trace!("Running model file garbage collection every {delay:?}");
+		// Create a new time-based interval ticket
+		let mut ticker = interval_ticker(delay).await;
+		// Loop continuously until the task is cancelled
+		loop {
+			tokio::select! {
+				biased;
+				// Check if this has shutdown
+				_ = canceller.cancelled() => break,
+				// Receive a notification on the channel
+				Some(_) = ticker.next() => {
+					if let Err(e) = dbs.ml_process().await {
+						error!("Error running model file garbage collection: {e}");
+					}
+				}
+			}
+		}
+		trace!("Background task exited: Running model file garbage collection");
+	}))
+}
+
 async fn interval_ticker(interval: Duration) -> IntervalStream {
 	#[cfg(not(target_family = "wasm"))]
 	use tokio::{time, time::MissedTickBehavior};
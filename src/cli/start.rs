@@ -65,6 +65,12 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_CHANGEFEED_GC_INTERVAL", long = "changefeed-gc-interval", value_parser = super::validator::duration)]
 	#[arg(default_value = "10s")]
 	changefeed_gc_interval: Duration,
+	#[arg(
+		help = "The interval at which to delete model files which are no longer used. Only enable this if the object store is not shared with any other datastore",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_ML_GC_INTERVAL", long = "ml-gc-interval", value_parser = super::validator::duration)]
+	ml_gc_interval: Option<Duration>,
 	//
 	// Authentication
 	//
@@ -162,6 +168,7 @@ pub async fn init(
 		node_membership_check_interval,
 		node_membership_cleanup_interval,
 		changefeed_gc_interval,
+		ml_gc_interval,
 		no_banner,
 		no_identification_headers,
 		..
@@ -189,7 +196,8 @@ pub async fn init(
 		.with_node_membership_refresh_interval(node_membership_refresh_interval)
 		.with_node_membership_check_interval(node_membership_check_interval)
 		.with_node_membership_cleanup_interval(node_membership_cleanup_interval)
-		.with_changefeed_gc_interval(changefeed_gc_interval);
+		.with_changefeed_gc_interval(changefeed_gc_interval)
+		.with_ml_gc_interval(ml_gc_interval);
 	// Configure the config
 	let config = Config {
 		bind: listen_addresses.first().copied().unwrap(),
@@ -211,6 +219,10 @@ pub async fn init(
 	#[cfg(feature = "ml")]
 	set_environment().map_err(|e| Error::MlInit(e.to_string()))?;
 
+	// if ML feature is enabled ensure the configured object stores are usable
+	#[cfg(feature = "ml")]
+	surrealdb::obs::init().await.map_err(|e| Error::ObsInit(e.to_string()))?;
+
 	// Create a token to cancel tasks
 	let canceller = CancellationToken::new();
 	// Start the datastore
@@ -53,6 +53,10 @@ pub static HTTP_MAX_RPC_BODY_SIZE: LazyLock<usize> =
 pub static HTTP_MAX_KEY_BODY_SIZE: LazyLock<usize> =
 	lazy_env_parse!(bytes, "SURREAL_HTTP_MAX_KEY_BODY_SIZE", usize, 16 << 10);
 
+/// The record field compared against the `since` option of the HTTP /key endpoints (default: "updated_at")
+pub static HTTP_KEY_SINCE_FIELD: LazyLock<String> =
+	lazy_env_parse!("SURREAL_HTTP_KEY_SINCE_FIELD", String, || "updated_at".to_string());
+
 /// The maximum HTTP body size of the HTTP /signup endpoint (default: 1 KiB)
 pub static HTTP_MAX_SIGNUP_BODY_SIZE: LazyLock<usize> =
 	lazy_env_parse!(bytes, "SURREAL_HTTP_MAX_SIGNUP_BODY_SIZE", usize, 1 << 10);
@@ -35,6 +35,9 @@ pub enum Error {
 	#[error("The specified media type is unsupported")]
 	InvalidType,
 
+	#[error("None of the requested media types are supported")]
+	NotAcceptable,
+
 	#[error("There was a problem connecting with the storage engine")]
 	InvalidStorage,
 
@@ -83,6 +86,9 @@ pub enum Error {
 
 	#[error("failed to init ML lib: {0}")]
 	MlInit(String),
+
+	#[error("failed to init object store: {0}")]
+	ObsInit(String),
 }
 
 impl From<Error> for String {
@@ -224,6 +230,15 @@ impl IntoResponse for Error {
 					information: None,
 				}),
 			),
+			Error::NotAcceptable => (
+				StatusCode::NOT_ACCEPTABLE,
+				Json(Message {
+					code: StatusCode::NOT_ACCEPTABLE.as_u16(),
+					details: Some("Not acceptable".to_string()),
+					description: Some("The requested response media type is not supported. Refer to the documentation for supported content types.".to_string()),
+					information: None,
+				}),
+			),
 			Error::InvalidStorage => (
 				StatusCode::INTERNAL_SERVER_ERROR,
 				Json(Message {
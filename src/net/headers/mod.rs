@@ -17,6 +17,7 @@ mod content_type;
 mod db;
 mod id;
 mod ns;
+mod scope;
 
 pub use accept::Accept;
 pub use auth_db::SurrealAuthDatabase;
@@ -25,6 +26,7 @@ pub use content_type::ContentType;
 pub use db::SurrealDatabase;
 pub use id::SurrealId;
 pub use ns::SurrealNamespace;
+pub use scope::SurrealScope;
 
 pub fn add_version_header(enabled: bool) -> SetResponseHeaderLayer<Option<HeaderValue>> {
 	let header_value = if enabled {
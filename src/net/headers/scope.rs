@@ -0,0 +1,53 @@
+use axum_extra::headers;
+use axum_extra::headers::Header;
+use http::HeaderName;
+use http::HeaderValue;
+use surrealdb::headers::SCOPE;
+
+/// Typed header implementation for the scope header.
+/// It's used to specify a token which narrows what a request may do.
+pub struct SurrealScope(String);
+
+impl Header for SurrealScope {
+	fn name() -> &'static HeaderName {
+		&SCOPE
+	}
+
+	fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+	where
+		I: Iterator<Item = &'i HeaderValue>,
+	{
+		let value = values.next().ok_or_else(headers::Error::invalid)?;
+		let value = value.to_str().map_err(|_| headers::Error::invalid())?.to_string();
+
+		Ok(SurrealScope(value))
+	}
+
+	fn encode<E>(&self, values: &mut E)
+	where
+		E: Extend<HeaderValue>,
+	{
+		values.extend(std::iter::once(self.into()));
+	}
+}
+
+impl std::ops::Deref for SurrealScope {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl From<SurrealScope> for HeaderValue {
+	fn from(value: SurrealScope) -> Self {
+		HeaderValue::from(&value)
+	}
+}
+
+#[expect(clippy::fallible_impl_from)]
+impl From<&SurrealScope> for HeaderValue {
+	fn from(value: &SurrealScope) -> Self {
+		HeaderValue::from_str(value.0.as_str()).unwrap()
+	}
+}
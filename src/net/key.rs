@@ -1,23 +1,40 @@
-use super::headers::Accept;
+use super::headers::{parse_typed_header, Accept, SurrealScope};
 use super::AppState;
+use crate::cnf::HTTP_KEY_SINCE_FIELD;
 use crate::cnf::HTTP_MAX_KEY_BODY_SIZE;
 use crate::err::Error;
 use crate::net::input::bytes_to_utf8;
 use crate::net::output;
+use crate::net::output::Output;
 use crate::net::params::Params;
-use axum::extract::{DefaultBodyLimit, Path};
+use axum::async_trait;
+use axum::extract::{DefaultBodyLimit, FromRequestParts, Path};
 use axum::response::IntoResponse;
 use axum::routing::options;
 use axum::Extension;
+use axum::RequestPartsExt;
 use axum::Router;
 use axum_extra::extract::Query;
+use axum_extra::headers::{CacheControl, IfModifiedSince, LastModified};
 use axum_extra::TypedHeader;
 use bytes::Bytes;
+use http::header::ACCEPT;
+use http::request::Parts;
+use http::StatusCode;
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
 use std::str;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use surrealdb::dbs::capabilities::RouteTarget;
 use surrealdb::dbs::Session;
 use surrealdb::iam::check::check_ns_db;
+use surrealdb::iam::verify::token;
+use surrealdb::kvs::Datastore;
+use surrealdb::kvs::{LockType::Optimistic, TransactionType::Read};
+use surrealdb::sql::Datetime;
+use surrealdb::sql::Object;
+use surrealdb::sql::Range;
 use surrealdb::sql::Value;
 use tower_http::limit::RequestBodyLimitLayer;
 
@@ -26,6 +43,176 @@ struct QueryOptions {
 	pub limit: Option<i64>,
 	pub start: Option<i64>,
 	pub fields: Option<Vec<String>>,
+	pub exclude: Option<Vec<String>>,
+	pub since: Option<String>,
+	pub from: Option<String>,
+	pub to: Option<String>,
+	pub explain: Option<bool>,
+	#[serde(default)]
+	pub idtype: IdType,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+struct RecordOptions {
+	pub explain: Option<bool>,
+	#[serde(default)]
+	pub idtype: IdType,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+struct UpdateOptions {
+	pub explain: Option<bool>,
+	#[serde(default)]
+	pub idtype: IdType,
+	#[serde(rename = "return")]
+	pub output: Option<String>,
+}
+
+/// How a Record ID in a key route request is interpreted.
+#[derive(Default, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum IdType {
+	/// Parsed as a SurrealQL value, falling back to a string
+	#[default]
+	Auto,
+	/// Parsed as an integer
+	Int,
+	/// Used as a string
+	String,
+}
+
+/// The response media type requested for a key route.
+///
+/// When no `Accept` header is sent, or when it allows any media type, the
+/// response is serialized as JSON. Otherwise the supported media type with the
+/// highest quality is used, preferring the earliest on a tie. Media types with
+/// a quality of zero are refused. When none of the requested media types can be
+/// served, the request is rejected as not acceptable.
+struct Format(Option<TypedHeader<Accept>>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Format
+where
+	S: Send + Sync,
+{
+	type Rejection = Error;
+
+	async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+		// Default to JSON when no media type is requested
+		let Some(value) = parts.headers.get(ACCEPT) else {
+			return Ok(Format(Some(TypedHeader(Accept::ApplicationJson))));
+		};
+		// Use the supported media type with the highest quality
+		let value = value.to_str().map_err(|_| Error::NotAcceptable)?;
+		let mut best: Option<(Accept, f32)> = None;
+		for range in value.split(',') {
+			let mut params = range.split(';');
+			let accept = match params.next().unwrap_or_default().trim() {
+				"" | "*/*" | "application/*" | "application/json" => Accept::ApplicationJson,
+				"application/cbor" => Accept::ApplicationCbor,
+				"application/surrealdb" => Accept::Surrealdb,
+				// No key route can serialize any other media type
+				_ => continue,
+			};
+			let quality = params
+				.filter_map(|v| v.trim().strip_prefix("q="))
+				.find_map(|v| v.trim().parse::<f32>().ok())
+				.unwrap_or(1.0);
+			if quality > 0.0 && best.as_ref().map_or(true, |(_, q)| quality > *q) {
+				best = Some((accept, quality));
+			}
+		}
+		match best {
+			Some((accept, _)) => Ok(Format(Some(TypedHeader(accept)))),
+			// None of the requested media types are supported
+			None => Err(Error::NotAcceptable),
+		}
+	}
+}
+
+/// The restrictions which a scoped token places on a request.
+///
+/// A scoped token is sent in the `surreal-scope` header, alongside the usual
+/// authentication, and must be signed by an access method which the datastore
+/// trusts. The request still runs with the rights of the authenticated session,
+/// so a scoped token can only narrow them: its `tables` and `methods` claims,
+/// when present, list the tables and the HTTP methods the request is limited to.
+///
+/// A route is checked against the operations which it performs, rather than
+/// against its HTTP method, so moving a record requires both `POST` and `DELETE`.
+struct Scope(Option<Object>);
+
+impl Scope {
+	/// Checks that the scoped token, when one was sent, permits these operations on a table.
+	fn check(&self, table: &str, methods: &[&str]) -> Result<(), Error> {
+		let Some(claims) = &self.0 else {
+			return Ok(());
+		};
+		if !permits(claims, "tables", |v| v == table)
+			|| !methods.iter().all(|m| permits(claims, "methods", |v| v.eq_ignore_ascii_case(m)))
+		{
+			return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
+		}
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Scope
+where
+	S: Send + Sync,
+{
+	type Rejection = Error;
+
+	async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+		// Check if a scoped token was specified
+		let Some(tk) =
+			parse_typed_header::<SurrealScope>(parts.extract::<TypedHeader<SurrealScope>>().await)?
+		else {
+			return Ok(Scope(None));
+		};
+		// Get the datastore and the session of the request
+		let Extension(state) =
+			parts.extract::<Extension<AppState>>().await.map_err(|_| Error::InvalidAuth)?;
+		let Extension(session) =
+			parts.extract::<Extension<Session>>().await.map_err(|_| Error::InvalidAuth)?;
+		// Verify the scoped token
+		let mut verified = Session::default();
+		token(&state.datastore, &mut verified, &tk).await?;
+		// Ensure that the scoped token is for the selected database
+		let denied = || Error::ForbiddenRoute(RouteTarget::Key.to_string());
+		if verified.ns.is_some() && verified.ns != session.ns
+			|| verified.db.is_some() && verified.db != session.db
+		{
+			return Err(denied());
+		}
+		// Get the restrictions of the scoped token
+		let Some(Value::Object(claims)) = verified.tk else {
+			return Err(denied());
+		};
+		let scope = Scope(Some(claims));
+		// Ensure that the scoped token permits the operations of this route
+		let Path(params) =
+			parts.extract::<Path<HashMap<String, String>>>().await.map_err(|_| Error::Request)?;
+		// A batch is checked against the operations which it contains, when it is run
+		if let Some(table) = params.get("table") {
+			match params.contains_key("new") {
+				// Moving a record creates the new record and deletes the old one
+				true => scope.check(table, &["POST", "DELETE"])?,
+				false => scope.check(table, &[parts.method.as_str()])?,
+			}
+		}
+		Ok(scope)
+	}
+}
+
+/// Checks whether a claim of a scoped token, when it is present, permits a value.
+fn permits(claims: &Object, claim: &str, f: impl Fn(&str) -> bool) -> bool {
+	match claims.get(claim) {
+		None => true,
+		Some(Value::Array(v)) => v.iter().any(|v| matches!(v, Value::Strand(v) if f(v.as_str()))),
+		Some(_) => false,
+	}
 }
 
 pub(super) fn router<S>() -> Router<S>
@@ -33,6 +220,7 @@ where
 	S: Clone + Send + Sync + 'static,
 {
 	Router::new()
+		.route("/key/batch", options(|| async {}).post(batch))
 		.route(
 			"/key/:table",
 			options(|| async {})
@@ -55,11 +243,200 @@ where
 						.patch(modify_one)
 						.delete(delete_one),
 				)
+				.route("/key/:table/:key/move/:new", options(|| async {}).post(move_one))
 				.route_layer(DefaultBodyLimit::disable())
 				.layer(RequestBodyLimitLayer::new(*HTTP_MAX_KEY_BODY_SIZE)),
 		)
 }
 
+/// Parses a Record ID from the request, as requested by the `idtype` option.
+///
+/// By default the Record ID is parsed as a SurrealQL value, falling back to a string.
+fn parse_id(id: String, idtype: IdType) -> Result<Value, Error> {
+	match idtype {
+		IdType::Auto => match surrealdb::sql::json(&id) {
+			Ok(id) => Ok(id),
+			Err(_) => Ok(Value::from(id)),
+		},
+		IdType::Int => id.parse::<i64>().map(Value::from).map_err(|_| Error::Request),
+		IdType::String => Ok(Value::from(id)),
+	}
+}
+
+/// Specifies the fields which are selected by a request.
+///
+/// Any requested `fields` take precedence, otherwise the `exclude` fields are removed from
+/// each record, so that large fields such as embeddings can be left out of the response.
+fn projection(query: &QueryOptions) -> &'static str {
+	match (&query.fields, &query.exclude) {
+		(Some(_), _) => "type::fields($fields)",
+		(None, Some(_)) => "VALUE object::remove($this, $exclude)",
+		(None, None) => "*",
+	}
+}
+
+/// Specifies the output of an update request.
+///
+/// By default the updated record is returned, while `both` returns the
+/// record as it was before and after the update, in a single response.
+fn returning(query: &UpdateOptions) -> Result<&'static str, Error> {
+	match query.output.as_deref() {
+		None => Ok(""),
+		Some("both") => Ok(" RETURN $before AS before, $after AS after"),
+		Some(_) => Err(Error::Request),
+	}
+}
+
+/// Finds when a table was last modified, from the change feed of its database.
+///
+/// Only tables with a change feed are cached, as otherwise deletions can not be tracked.
+/// The time is when the change feed first recorded a timestamp after its latest change,
+/// to any table in the database, so no time is returned until such a timestamp exists.
+async fn last_modified(
+	db: &Datastore,
+	ns: &str,
+	dbv: &str,
+	table: &str,
+) -> Result<Option<SystemTime>, Error> {
+	let tx = db.transaction(Read, Optimistic).await?;
+	let res = match tx.get_tb(ns, dbv, table).await {
+		Ok(tb) if tb.changefeed.is_some() => tx.get_changefeed_modified(ns, dbv).await,
+		_ => Ok(None),
+	};
+	tx.cancel().await?;
+	Ok(res?.map(|v| UNIX_EPOCH + Duration::from_secs(v)))
+}
+
+/// Describes the statement which a request would execute, without executing it.
+///
+/// The bound variables are listed by name, with each value replaced by its type.
+fn explain(
+	accept: Option<&Accept>,
+	sql: &str,
+	vars: &BTreeMap<String, Value>,
+) -> Result<Output, Error> {
+	// Redact the values of the bound variables
+	let vars: BTreeMap<String, Value> =
+		vars.iter().map(|(k, v)| (k.clone(), Value::from(v.kindof()))).collect();
+	// Specify the explained statement
+	let res = Value::from(map! {
+		String::from("sql") => Value::from(sql),
+		String::from("vars") => Value::from(vars),
+	});
+	// Return the explained statement
+	match accept {
+		// Simple serialization
+		Some(Accept::ApplicationJson) => Ok(output::json(&output::simplify(res)?)),
+		Some(Accept::ApplicationCbor) => Ok(output::cbor(&output::simplify(res)?)),
+		// Internal serialization
+		Some(Accept::Surrealdb) => Ok(output::full(&res)),
+		// An incorrect content-type was requested
+		_ => Err(Error::InvalidType),
+	}
+}
+
+// ------------------------------
+// Routes for a batch
+// ------------------------------
+
+/// Runs a batch of key operations within a single transaction.
+///
+/// The request body is an array of operations, each with a `method`, a `table`,
+/// an optional `key`, and an optional `body`, which are run as the matching key
+/// route would run them. A result is returned for each operation, and if any of
+/// the operations fails then none of the changes are committed. A scoped token
+/// must permit every operation in the batch. Selecting a table returns at most
+/// 100 records, as with the table route.
+///
+/// As this is served from `/key/batch`, the table routes can not be used for a
+/// table named `batch`, whose records are still reached through the record routes.
+async fn batch(
+	Extension(state): Extension<AppState>,
+	Extension(session): Extension<Session>,
+	Format(accept): Format,
+	scope: Scope,
+	Query(query): Query<RecordOptions>,
+	body: Bytes,
+) -> Result<impl IntoResponse, impl IntoResponse> {
+	// Get the datastore reference
+	let db = &state.datastore;
+	// Check if capabilities allow querying the requested HTTP route
+	if !db.allows_http_route(&RouteTarget::Key) {
+		warn!("Capabilities denied HTTP route request attempt, target: '{}'", &RouteTarget::Key);
+		return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
+	}
+	// Check if the user is allowed to query
+	if !db.allows_query_by_subject(session.au.as_ref()) {
+		return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
+	}
+	// Ensure a NS and DB are set
+	let _ = check_ns_db(&session)?;
+	// Convert the HTTP request body
+	let data = bytes_to_utf8(&body)?;
+	// Parse the request body as an array of operations
+	let Ok(Value::Array(ops)) = surrealdb::sql::value(data) else {
+		return Err(Error::Request);
+	};
+	// Specify the request statements and variables
+	let mut sql = String::from("BEGIN TRANSACTION;\n");
+	let mut vars = map! {
+		String::from("limit") => Value::from(100),
+	};
+	for (i, op) in ops.into_iter().enumerate() {
+		let Value::Object(mut op) = op else {
+			return Err(Error::Request);
+		};
+		let method = match op.remove("method") {
+			Some(Value::Strand(v)) => v.0.to_ascii_uppercase(),
+			_ => return Err(Error::Request),
+		};
+		let table = match op.remove("table") {
+			Some(Value::Strand(v)) => v.0,
+			_ => return Err(Error::Request),
+		};
+		let key = op.remove("key");
+		let stm = match (method.as_str(), key.is_some()) {
+			("GET", false) => format!("SELECT * FROM type::table($table{i}) LIMIT $limit"),
+			("GET", true) => format!("SELECT * FROM type::thing($table{i}, $key{i})"),
+			("POST", false) => format!("CREATE type::table($table{i}) CONTENT $data{i}"),
+			("POST", true) => format!("CREATE type::thing($table{i}, $key{i}) CONTENT $data{i}"),
+			("PUT", false) => format!("UPDATE type::table($table{i}) CONTENT $data{i}"),
+			("PUT", true) => format!("UPSERT type::thing($table{i}, $key{i}) CONTENT $data{i}"),
+			("PATCH", false) => format!("UPDATE type::table($table{i}) MERGE $data{i}"),
+			("PATCH", true) => format!("UPSERT type::thing($table{i}, $key{i}) MERGE $data{i}"),
+			("DELETE", false) => format!("DELETE type::table($table{i}) RETURN BEFORE"),
+			("DELETE", true) => format!("DELETE type::thing($table{i}, $key{i}) RETURN BEFORE"),
+			_ => return Err(Error::Request),
+		};
+		// Check that the scoped token permits this operation
+		scope.check(&table, &[method.as_str()])?;
+		sql.push_str(&stm);
+		sql.push_str(";\n");
+		vars.insert(format!("table{i}"), Value::from(table));
+		vars.insert(format!("key{i}"), key.unwrap_or_default());
+		vars.insert(format!("data{i}"), op.remove("body").unwrap_or_default());
+	}
+	sql.push_str("COMMIT TRANSACTION;");
+	// Return the statements without executing them
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), &sql, &vars);
+	}
+	// Execute the query and return the result
+	match db.execute(&sql, &session, Some(vars)).await {
+		Ok(res) => match accept.as_deref() {
+			// Simple serialization
+			Some(Accept::ApplicationJson) => Ok(output::json(&output::simplify(res)?)),
+			Some(Accept::ApplicationCbor) => Ok(output::cbor(&output::simplify(res)?)),
+			// Internal serialization
+			Some(Accept::Surrealdb) => Ok(output::full(&res)),
+			// An incorrect content-type was requested
+			_ => Err(Error::InvalidType),
+		},
+		// There was an error when executing the query
+		Err(err) => Err(Error::from(err)),
+	}
+}
+
 // ------------------------------
 // Routes for a table
 // ------------------------------
@@ -67,9 +444,11 @@ where
 async fn select_all(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path(table): Path<String>,
 	Query(query): Query<QueryOptions>,
+	if_modified_since: Option<TypedHeader<IfModifiedSince>>,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
 	// Get the datastore reference
 	let db = &state.datastore;
@@ -83,19 +462,63 @@ async fn select_all(
 		return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
 	}
 	// Ensure a NS and DB are set
-	let _ = check_ns_db(&session)?;
+	let (nsv, dbv) = check_ns_db(&session)?;
+	// Specify the selected fields
+	let expr = projection(&query);
+	// Parse the modification cutoff as a datetime
+	let since = match query.since {
+		Some(v) => Some(Datetime::try_from(v.as_str()).map_err(|_| Error::Request)?),
+		None => None,
+	};
+	// Parse the Record ID range bounds
+	let idtype = query.idtype;
+	let range = match (query.from, query.to) {
+		(None, None) => None,
+		(beg, end) => Some(Range::new(
+			beg.map(|v| parse_id(v, idtype).map(Bound::Included))
+				.transpose()?
+				.unwrap_or(Bound::Unbounded),
+			end.map(|v| parse_id(v, idtype).map(Bound::Included))
+				.transpose()?
+				.unwrap_or(Bound::Unbounded),
+		)),
+	};
 	// Specify the request statement
-	let sql = match query.fields {
-		None => "SELECT * FROM type::table($table) LIMIT $limit START $start",
-		_ => "SELECT type::fields($fields) FROM type::table($table) LIMIT $limit START $start",
+	let what = match range {
+		None => "type::table($table)",
+		_ => "type::thing($table, $range)",
+	};
+	let cond = match since {
+		None => "",
+		_ => " WHERE type::field($field) > $since",
 	};
+	let sql = &format!("SELECT {expr} FROM {what}{cond} LIMIT $limit START $start");
 	// Specify the request variables
 	let vars = map! {
-		String::from("table") => Value::from(table),
+		String::from("table") => Value::from(table.as_str()),
 		String::from("start") => Value::from(query.start.unwrap_or(0)),
 		String::from("limit") => Value::from(query.limit.unwrap_or(100)),
 		String::from("fields") => Value::from(query.fields.unwrap_or_default()),
+		String::from("exclude") => Value::from(query.exclude.unwrap_or_default()),
+		String::from("field") => Value::from(HTTP_KEY_SINCE_FIELD.as_str()),
+		String::from("since") => since.map(Value::from).unwrap_or_default(),
+		String::from("range") => range.map(|v| Value::Range(Box::new(v))).unwrap_or_default(),
 	};
+	// Return the statement without executing it
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), sql, &vars).map(IntoResponse::into_response);
+	}
+	// Specify the caching headers
+	let modified = last_modified(db, &nsv, &dbv, &table).await?;
+	let headers = modified.map(|v| {
+		(TypedHeader(CacheControl::new().with_no_cache()), TypedHeader(LastModified::from(v)))
+	});
+	// Check if the table has changed since it was last requested
+	if let (Some(TypedHeader(since)), Some(v)) = (if_modified_since, modified) {
+		if !since.is_modified(v) {
+			return Ok((StatusCode::NOT_MODIFIED, headers).into_response());
+		}
+	}
 	// Execute the query and return the result
 	match db.execute(sql, &session, Some(vars)).await {
 		Ok(res) => match accept.as_deref() {
@@ -107,7 +530,8 @@ async fn select_all(
 			Some(Accept::Surrealdb) => Ok(output::full(&res)),
 			// An incorrect content-type was requested
 			_ => Err(Error::InvalidType),
-		},
+		}
+		.map(|v| (headers, v).into_response()),
 		// There was an error when executing the query
 		Err(err) => Err(Error::from(err)),
 	}
@@ -116,8 +540,10 @@ async fn select_all(
 async fn create_all(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path(table): Path<String>,
+	Query(query): Query<RecordOptions>,
 	Query(params): Query<Params>,
 	body: Bytes,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
@@ -147,6 +573,10 @@ async fn create_all(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -169,8 +599,10 @@ async fn create_all(
 async fn update_all(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path(table): Path<String>,
+	Query(query): Query<RecordOptions>,
 	Query(params): Query<Params>,
 	body: Bytes,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
@@ -200,6 +632,10 @@ async fn update_all(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -222,8 +658,10 @@ async fn update_all(
 async fn modify_all(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path(table): Path<String>,
+	Query(query): Query<RecordOptions>,
 	Query(params): Query<Params>,
 	body: Bytes,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
@@ -253,6 +691,10 @@ async fn modify_all(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -275,8 +717,10 @@ async fn modify_all(
 async fn delete_all(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path(table): Path<String>,
+	Query(query): Query<RecordOptions>,
 	Query(params): Query<Params>,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
 	// Get the datastore reference
@@ -299,6 +743,10 @@ async fn delete_all(
 		String::from("table") => Value::from(table),
 		=> params.parse()
 	};
+	// Return the statement without executing it
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), sql, &vars);
+	}
 	// Execute the query and return the result
 	match db.execute(sql, &session, Some(vars)).await {
 		Ok(res) => match accept.as_deref() {
@@ -322,7 +770,8 @@ async fn delete_all(
 async fn select_one(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path((table, id)): Path<(String, String)>,
 	Query(query): Query<QueryOptions>,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
@@ -340,21 +789,21 @@ async fn select_one(
 	// Ensure a NS and DB are set
 	let _ = check_ns_db(&session)?;
 	// Specify the request statement
-	let sql = match query.fields {
-		None => "SELECT * FROM type::thing($table, $id)",
-		_ => "SELECT type::fields($fields) FROM type::thing($table, $id)",
-	};
-	// Parse the Record ID as a SurrealQL value
-	let rid = match surrealdb::sql::json(&id) {
-		Ok(id) => id,
-		Err(_) => Value::from(id),
-	};
+	let expr = projection(&query);
+	let sql = &format!("SELECT {expr} FROM type::thing($table, $id)");
+	// Parse the Record ID
+	let rid = parse_id(id, query.idtype)?;
 	// Specify the request variables
 	let vars = map! {
 		String::from("table") => Value::from(table),
 		String::from("id") => rid,
 		String::from("fields") => Value::from(query.fields.unwrap_or_default()),
+		String::from("exclude") => Value::from(query.exclude.unwrap_or_default()),
 	};
+	// Return the statement without executing it
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), sql, &vars);
+	}
 	// Execute the query and return the result
 	match db.execute(sql, &session, Some(vars)).await {
 		Ok(res) => match accept.as_deref() {
@@ -374,7 +823,9 @@ async fn select_one(
 async fn create_one(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
+	Query(query): Query<RecordOptions>,
 	Query(params): Query<Params>,
 	Path((table, id)): Path<(String, String)>,
 	body: Bytes,
@@ -394,11 +845,8 @@ async fn create_one(
 	let _ = check_ns_db(&session)?;
 	// Convert the HTTP request body
 	let data = bytes_to_utf8(&body)?;
-	// Parse the Record ID as a SurrealQL value
-	let rid = match surrealdb::sql::json(&id) {
-		Ok(id) => id,
-		Err(_) => Value::from(id),
-	};
+	// Parse the Record ID
+	let rid = parse_id(id, query.idtype)?;
 	// Parse the request body as JSON
 	match surrealdb::sql::value(data) {
 		Ok(data) => {
@@ -411,6 +859,10 @@ async fn create_one(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -433,7 +885,9 @@ async fn create_one(
 async fn update_one(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
+	Query(query): Query<UpdateOptions>,
 	Query(params): Query<Params>,
 	Path((table, id)): Path<(String, String)>,
 	body: Bytes,
@@ -453,16 +907,15 @@ async fn update_one(
 	let _ = check_ns_db(&session)?;
 	// Convert the HTTP request body
 	let data = bytes_to_utf8(&body)?;
-	// Parse the Record ID as a SurrealQL value
-	let rid = match surrealdb::sql::json(&id) {
-		Ok(id) => id,
-		Err(_) => Value::from(id),
-	};
+	// Parse the Record ID
+	let rid = parse_id(id, query.idtype)?;
+	// Specify the returned output
+	let ret = returning(&query)?;
 	// Parse the request body as JSON
 	match surrealdb::sql::value(data) {
 		Ok(data) => {
 			// Specify the request statement
-			let sql = "UPSERT type::thing($table, $id) CONTENT $data";
+			let sql = &format!("UPSERT type::thing($table, $id) CONTENT $data{ret}");
 			// Specify the request variables
 			let vars = map! {
 				String::from("table") => Value::from(table),
@@ -470,6 +923,10 @@ async fn update_one(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -492,7 +949,9 @@ async fn update_one(
 async fn modify_one(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
+	Query(query): Query<UpdateOptions>,
 	Query(params): Query<Params>,
 	Path((table, id)): Path<(String, String)>,
 	body: Bytes,
@@ -512,16 +971,15 @@ async fn modify_one(
 	let _ = check_ns_db(&session)?;
 	// Convert the HTTP request body
 	let data = bytes_to_utf8(&body)?;
-	// Parse the Record ID as a SurrealQL value
-	let rid = match surrealdb::sql::json(&id) {
-		Ok(id) => id,
-		Err(_) => Value::from(id),
-	};
+	// Parse the Record ID
+	let rid = parse_id(id, query.idtype)?;
+	// Specify the returned output
+	let ret = returning(&query)?;
 	// Parse the request body as JSON
 	match surrealdb::sql::value(data) {
 		Ok(data) => {
 			// Specify the request statement
-			let sql = "UPSERT type::thing($table, $id) MERGE $data";
+			let sql = &format!("UPSERT type::thing($table, $id) MERGE $data{ret}");
 			// Specify the request variables
 			let vars = map! {
 				String::from("table") => Value::from(table),
@@ -529,6 +987,10 @@ async fn modify_one(
 				String::from("data") => data,
 				=> params.parse()
 			};
+			// Return the statement without executing it
+			if query.explain.unwrap_or_default() {
+				return explain(accept.as_deref(), sql, &vars);
+			}
 			// Execute the query and return the result
 			match db.execute(sql, &session, Some(vars)).await {
 				Ok(res) => match accept.as_deref() {
@@ -551,8 +1013,10 @@ async fn modify_one(
 async fn delete_one(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
-	accept: Option<TypedHeader<Accept>>,
+	Format(accept): Format,
+	_: Scope,
 	Path((table, id)): Path<(String, String)>,
+	Query(query): Query<RecordOptions>,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
 	// Get the datastore reference
 	let db = &state.datastore;
@@ -569,16 +1033,75 @@ async fn delete_one(
 	let _ = check_ns_db(&session)?;
 	// Specify the request statement
 	let sql = "DELETE type::thing($table, $id) RETURN BEFORE";
-	// Parse the Record ID as a SurrealQL value
-	let rid = match surrealdb::sql::json(&id) {
-		Ok(id) => id,
-		Err(_) => Value::from(id),
+	// Parse the Record ID
+	let rid = parse_id(id, query.idtype)?;
+	// Specify the request variables
+	let vars = map! {
+		String::from("table") => Value::from(table),
+		String::from("id") => rid,
 	};
+	// Return the statement without executing it
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), sql, &vars);
+	}
+	// Execute the query and return the result
+	match db.execute(sql, &session, Some(vars)).await {
+		Ok(res) => match accept.as_deref() {
+			// Simple serialization
+			Some(Accept::ApplicationJson) => Ok(output::json(&output::simplify(res)?)),
+			Some(Accept::ApplicationCbor) => Ok(output::cbor(&output::simplify(res)?)),
+			// Internal serialization
+			Some(Accept::Surrealdb) => Ok(output::full(&res)),
+			// An incorrect content-type was requested
+			_ => Err(Error::InvalidType),
+		},
+		// There was an error when executing the query
+		Err(err) => Err(Error::from(err)),
+	}
+}
+
+async fn move_one(
+	Extension(state): Extension<AppState>,
+	Extension(session): Extension<Session>,
+	Format(accept): Format,
+	_: Scope,
+	Path((table, id, key)): Path<(String, String, String)>,
+	Query(query): Query<RecordOptions>,
+) -> Result<impl IntoResponse, impl IntoResponse> {
+	// Get the datastore reference
+	let db = &state.datastore;
+	// Check if capabilities allow querying the requested HTTP route
+	if !db.allows_http_route(&RouteTarget::Key) {
+		warn!("Capabilities denied HTTP route request attempt, target: '{}'", &RouteTarget::Key);
+		return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
+	}
+	// Check if the user is allowed to query
+	if !db.allows_query_by_subject(session.au.as_ref()) {
+		return Err(Error::ForbiddenRoute(RouteTarget::Key.to_string()));
+	}
+	// Ensure a NS and DB are set
+	let _ = check_ns_db(&session)?;
+	// Specify the request statement, which runs within a single transaction
+	let sql = "{
+		LET $before = SELECT * FROM ONLY type::thing($table, $id);
+		IF $before = NONE { THROW 'The record to move does not exist' };
+		LET $after = CREATE ONLY type::thing($table, $key) CONTENT object::remove($before, 'id');
+		DELETE type::thing($table, $id);
+		RETURN $after;
+	}";
+	// Parse the Record IDs
+	let rid = parse_id(id, query.idtype)?;
+	let key = parse_id(key, query.idtype)?;
 	// Specify the request variables
 	let vars = map! {
 		String::from("table") => Value::from(table),
 		String::from("id") => rid,
+		String::from("key") => key,
 	};
+	// Return the statement without executing it
+	if query.explain.unwrap_or_default() {
+		return explain(accept.as_deref(), sql, &vars);
+	}
 	// Execute the query and return the result
 	match db.execute(sql, &session, Some(vars)).await {
 		Ok(res) => match accept.as_deref() {
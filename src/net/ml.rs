@@ -86,14 +86,8 @@ async fn import(
 	let data = file.to_bytes();
 	// Calculate the hash of the model file
 	let hash = surrealdb::obs::hash(&data);
-	// Calculate the path of the model file
-	let path = format!(
-		"ml/{nsv}/{dbv}/{}-{}-{hash}.surml",
-		file.header.name.to_string(),
-		file.header.version.to_string()
-	);
-	// Insert the file data in to the store
-	surrealdb::obs::put(&path, data).await?;
+	// Insert the file data in to the store, refreshing it if already stored
+	surrealdb::obs::put_model(&hash, data).await?;
 	// Insert the model in to the database
 	let mut model = DefineModelStatement::default();
 	model.name = file.header.name.to_string().into();
@@ -127,10 +121,8 @@ async fn export(
 	let tx = db.transaction(Read, Optimistic).await?;
 	// Attempt to get the model definition
 	let info = tx.get_db_model(&nsv, &dbv, &name, &version).await?;
-	// Calculate the path of the model file
-	let path = format!("ml/{nsv}/{dbv}/{name}-{version}-{}.surml", info.hash);
 	// Export the file data in to the store
-	let mut data = surrealdb::obs::stream(path).await?;
+	let mut data = surrealdb::obs::stream_model(&nsv, &dbv, &name, &version, &info.hash).await?;
 	// Create a chunked response
 	let (chn, body_stream) = surrealdb::channel::bounded::<Result<Bytes, Error>>(1);
 	let body = Body::from_stream(body_stream);
@@ -1145,6 +1145,148 @@ mod http_integration {
 		Ok(())
 	}
 
+	#[test(tokio::test)]
+	async fn key_endpoint_select_all_since() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table with modification timestamps
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					CREATE table:1 SET updated_at = d"2024-01-01T00:00:00Z";
+					CREATE table:2 SET updated_at = d"2024-02-01T00:00:00Z";
+					CREATE table:3 SET updated_at = d"2024-03-01T00:00:00Z";
+					CREATE table:4 SET updated_at = d"2024-04-01T00:00:00Z";
+					"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// GET records modified since a timestamp
+		{
+			let res = client
+				.get(format!("{url}?since=2024-02-15T00:00:00Z"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 2, "body: {body}");
+			assert_eq!(result[0]["id"], "table:3", "body: {body}");
+			assert_eq!(result[1]["id"], "table:4", "body: {body}");
+		}
+
+		// GET records modified since a timestamp with fields
+		{
+			let res = client
+				.get(format!("{url}?since=2024-03-15T00:00:00Z&fields=id"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 1, "body: {body}");
+		}
+
+		// GET records with an invalid timestamp
+		{
+			let res = client
+				.get(format!("{url}?since=yesterday"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_select_all_range() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let num_records = 50;
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table
+		seed_table(&client, &addr, table_name, num_records).await?;
+
+		// GET records within an inclusive range
+		{
+			let res = client
+				.get(format!("{url}?from=10&to=20"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 11, "body: {body}");
+			assert_eq!(result[0]["id"], "table:10", "body: {body}");
+			assert_eq!(result[10]["id"], "table:20", "body: {body}");
+		}
+
+		// GET records from a lower bound
+		{
+			let res =
+				client.get(format!("{url}?from=45")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 6, "body: {body}");
+			assert_eq!(result[0]["id"], "table:45", "body: {body}");
+		}
+
+		// GET records up to an upper bound with a limit
+		{
+			let res = client
+				.get(format!("{url}?to=20&limit=5"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 5, "body: {body}");
+			assert_eq!(result[0]["id"], "table:1", "body: {body}");
+		}
+
+		Ok(())
+	}
+
 	#[test(tokio::test)]
 	async fn key_endpoint_create_all() -> Result<(), Box<dyn std::error::Error>> {
 		let (addr, _server) = common::start_server_with_guests().await.unwrap();
@@ -1437,6 +1579,77 @@ mod http_integration {
 		Ok(())
 	}
 
+	#[test(tokio::test)]
+	async fn key_endpoint_select_exclude() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table with embeddings
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					CREATE table:1 SET name = 'one', embedding = [0.1, 0.2, 0.3], extra = true;
+					CREATE table:2 SET name = 'two', embedding = [0.4, 0.5, 0.6], extra = true;
+					"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// GET all records without the excluded fields
+		{
+			let res = client
+				.get(format!("{url}?exclude=embedding&exclude=extra"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 2, "body: {body}");
+			for record in result {
+				assert!(record.get("embedding").is_none(), "body: {body}");
+				assert!(record.get("extra").is_none(), "body: {body}");
+				assert!(record.get("id").is_some(), "body: {body}");
+				assert!(record.get("name").is_some(), "body: {body}");
+			}
+		}
+
+		// GET one record without the excluded field
+		{
+			let res = client
+				.get(format!("{url}/1?exclude=embedding"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let record = &body[0]["result"][0];
+			assert!(record.get("embedding").is_none(), "body: {body}");
+			assert_eq!(record["name"], "one", "body: {body}");
+			assert_eq!(record["extra"], true, "body: {body}");
+		}
+
+		Ok(())
+	}
+
 	#[test(tokio::test)]
 	async fn key_endpoint_create_one() -> Result<(), Box<dyn std::error::Error>> {
 		let (addr, _server) = common::start_server_with_guests().await.unwrap();
@@ -1725,6 +1938,831 @@ mod http_integration {
 		Ok(())
 	}
 
+	#[test(tokio::test)]
+	async fn key_endpoint_move_one() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table
+		seed_table(&client, &addr, table_name, 2).await?;
+
+		// Move a record to a new ID
+		{
+			let res =
+				client.post(format!("{url}/1/move/3")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "OK", "body: {body}");
+			assert_eq!(body[0]["result"]["id"], "table:3", "body: {body}");
+			assert_eq!(body[0]["result"]["default"], "content", "body: {body}");
+		}
+
+		// The record exists under the new ID
+		{
+			let res = client.get(format!("{url}/3")).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 1, "body: {body}");
+		}
+
+		// The record no longer exists under the old ID
+		{
+			let res = client.get(format!("{url}/1")).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 0, "body: {body}");
+		}
+
+		// Moving a record onto an existing ID fails and changes nothing
+		{
+			let res =
+				client.post(format!("{url}/2/move/3")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "ERR", "body: {body}");
+
+			let res = client.get(url).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 2, "body: {body}");
+			assert_eq!(result[0]["id"], "table:2", "body: {body}");
+			assert_eq!(result[1]["id"], "table:3", "body: {body}");
+		}
+
+		// Moving a record which does not exist fails
+		{
+			let res =
+				client.post(format!("{url}/9/move/10")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "ERR", "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_explain() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// POST with explain returns the statement without executing it
+		{
+			let res = client
+				.post(format!("{url}?explain=true"))
+				.basic_auth(USER, Some(PASS))
+				.body(r#"{"name": "record_name"}"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body["sql"], "CREATE type::table($table) CONTENT $data", "body: {body}");
+			assert_eq!(body["vars"]["table"], "string", "body: {body}");
+			assert_eq!(body["vars"]["data"], "object", "body: {body}");
+			assert!(!body.to_string().contains("record_name"), "body: {body}");
+		}
+
+		// GET with explain returns the statement without executing it
+		{
+			let res = client
+				.get(format!("{url}/1?explain=true"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body["sql"], "SELECT * FROM type::thing($table, $id)", "body: {body}");
+			assert_eq!(body["vars"]["id"], "int", "body: {body}");
+		}
+
+		// The explained request was not executed
+		{
+			let res = client.get(url).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 0, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_accept_fallback() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client without an Accept header
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// POST without an Accept header defaults to JSON
+		for _ in 0..5 {
+			let res = client.post(url).basic_auth(USER, Some(PASS)).body("{}").send().await?;
+			assert_eq!(res.status(), 200);
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+		}
+
+		// GET without an Accept header defaults to JSON
+		{
+			let res = client.get(url).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200);
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 5, "body: {body}");
+		}
+
+		// GET with a wildcard Accept header defaults to JSON
+		{
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::ACCEPT, "*/*")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 5, "body: {body}");
+		}
+
+		// GET with a list of media types uses the first supported one
+		{
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::ACCEPT, "text/html, application/cbor;q=0.9, */*;q=0.8")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/cbor");
+		}
+
+		// GET with a media type which can not be served skips to the next one
+		for accept in ["text/plain, application/json", "text/plain, */*"] {
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::ACCEPT, accept)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "accept: {accept}");
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+		}
+
+		// GET with a refused media type uses the next supported one
+		{
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::ACCEPT, "application/json;q=0, application/cbor")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			assert_eq!(res.headers()[header::CONTENT_TYPE], "application/cbor");
+		}
+
+		// GET with an unsupported Accept header returns a 406
+		for accept in ["text/xml", "text/plain", "application/json;q=0"] {
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::ACCEPT, accept)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 406, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_update_return_both() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/{table_name}");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table
+		seed_table(&client, &addr, table_name, 1).await?;
+
+		// Update a record, returning the record before and after the update
+		{
+			let res = client
+				.put(format!("{url}/1?return=both"))
+				.basic_auth(USER, Some(PASS))
+				.body(r#"{ "name": "updated" }"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "OK", "body: {body}");
+			let result = &body[0]["result"][0];
+			assert_eq!(result["before"]["default"], "content", "body: {body}");
+			assert!(result["before"]["name"].is_null(), "body: {body}");
+			assert!(result["after"]["default"].is_null(), "body: {body}");
+			assert_eq!(result["after"]["name"], "updated", "body: {body}");
+		}
+
+		// Modify a record, returning the record before and after the modification
+		{
+			let res = client
+				.patch(format!("{url}/1?return=both"))
+				.basic_auth(USER, Some(PASS))
+				.body(r#"{ "default": "modified" }"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "OK", "body: {body}");
+			let result = &body[0]["result"][0];
+			assert!(result["before"]["default"].is_null(), "body: {body}");
+			assert_eq!(result["before"]["name"], "updated", "body: {body}");
+			assert_eq!(result["after"]["default"], "modified", "body: {body}");
+			assert_eq!(result["after"]["name"], "updated", "body: {body}");
+		}
+
+		// An unknown return option is rejected
+		{
+			let res = client
+				.put(format!("{url}/1?return=unknown"))
+				.basic_auth(USER, Some(PASS))
+				.body(r#"{ "name": "unknown" }"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_select_idtype() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let url = &format!("http://{addr}/key/table");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed records with an integer and a string Record ID
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("CREATE table:42 SET kind = 'int'; CREATE table:⟨42⟩ SET kind = 'string';")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// Each mode retrieves the expected record
+		for (idtype, kind) in [
+			("", "int"),
+			("?idtype=auto", "int"),
+			("?idtype=int", "int"),
+			("?idtype=string", "string"),
+		] {
+			let res =
+				client.get(format!("{url}/42{idtype}")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"][0]["kind"], kind, "idtype: {idtype}, body: {body}");
+		}
+
+		// A non-integer Record ID is rejected in integer mode
+		{
+			let res = client
+				.get(format!("{url}/abc?idtype=int"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "body: {}", res.text().await?);
+		}
+
+		// An unknown mode is rejected
+		{
+			let res = client
+				.get(format!("{url}/42?idtype=unknown"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_batch() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let table_name = "table";
+		let url = &format!("http://{addr}/key/batch");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the table
+		seed_table(&client, &addr, table_name, 1).await?;
+
+		// Run a batch of operations which all succeed
+		{
+			let res = client
+				.post(url)
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"[
+						{ "method": "POST", "table": "table", "key": 2, "body": { "name": "created" } },
+						{ "method": "PATCH", "table": "table", "key": 1, "body": { "name": "updated" } },
+						{ "method": "GET", "table": "table" }
+					]"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body.as_array().unwrap().len(), 3, "body: {body}");
+			assert_eq!(body[0]["status"], "OK", "body: {body}");
+			assert_eq!(body[0]["result"][0]["id"], "table:2", "body: {body}");
+			assert_eq!(body[1]["status"], "OK", "body: {body}");
+			assert_eq!(body[1]["result"][0]["name"], "updated", "body: {body}");
+			assert_eq!(body[2]["status"], "OK", "body: {body}");
+			assert_eq!(body[2]["result"].as_array().unwrap().len(), 2, "body: {body}");
+		}
+
+		// Run a batch of operations where a later operation fails
+		{
+			let res = client
+				.post(url)
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"[
+						{ "method": "POST", "table": "table", "key": 3, "body": { "name": "created" } },
+						{ "method": "DELETE", "table": "table", "key": 1 },
+						{ "method": "POST", "table": "table", "key": 2, "body": { "name": "duplicate" } }
+					]"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body.as_array().unwrap().len(), 3, "body: {body}");
+			assert_eq!(body[0]["status"], "ERR", "body: {body}");
+			assert_eq!(body[1]["status"], "ERR", "body: {body}");
+			assert_eq!(body[2]["status"], "ERR", "body: {body}");
+		}
+
+		// The earlier operations of the failed batch were rolled back
+		{
+			let res = client
+				.get(format!("http://{addr}/key/{table_name}"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			let result = body[0]["result"].as_array().unwrap();
+			assert_eq!(result.len(), 2, "body: {body}");
+			assert_eq!(result[0]["id"], "table:1", "body: {body}");
+			assert_eq!(result[1]["id"], "table:2", "body: {body}");
+			assert_eq!(result[1]["name"], "created", "body: {body}");
+		}
+
+		// A table named batch is still accessed through the record routes
+		{
+			let res = client
+				.post(format!("{url}/1"))
+				.basic_auth(USER, Some(PASS))
+				.body(r#"{ "name": "record" }"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let res = client.get(format!("{url}/1")).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"][0]["name"], "record", "body: {body}");
+		}
+
+		// Selecting a table in a batch is limited like selecting a table
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("FOR $i IN 0..150 { CREATE many; };")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let res = client
+				.post(url)
+				.basic_auth(USER, Some(PASS))
+				.body(r#"[{ "method": "GET", "table": "many" }]"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 100, "body: {body}");
+		}
+
+		// An invalid operation is rejected
+		{
+			let res = client
+				.post(url)
+				.basic_auth(USER, Some(PASS))
+				.body(r#"[{ "method": "UNKNOWN", "table": "table" }]"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_select_all_cache() -> Result<(), Box<dyn std::error::Error>> {
+		// Record the change feed timestamps every second
+		let (addr, _server) = common::start_server(StartServerArguments {
+			args: "--changefeed-gc-interval 1s".to_string(),
+			..Default::default()
+		})
+		.await
+		.unwrap();
+		let url = &format!("http://{addr}/key/table");
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed records in tables with and without a change feed
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body(
+					"DEFINE TABLE table CHANGEFEED 1h; CREATE table:1 SET name = 'one'; CREATE other:1;",
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// Wait until the change feed has a timestamp after the changes
+		tokio::time::sleep(Duration::from_millis(2500)).await;
+
+		// The response specifies when the table was last modified
+		let modified = {
+			let res = client.get(url).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200);
+			assert!(res.headers().contains_key(header::CACHE_CONTROL));
+			res.headers().get(header::LAST_MODIFIED).cloned().expect("missing Last-Modified header")
+		};
+
+		// An unchanged table is not sent again
+		{
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::IF_MODIFIED_SINCE, modified.clone())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 304);
+			assert_eq!(res.headers().get(header::LAST_MODIFIED), Some(&modified));
+		}
+
+		// Delete a record in the table
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("DELETE table:1;")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// Wait until the change feed has a timestamp after the deletion
+		tokio::time::sleep(Duration::from_millis(2500)).await;
+
+		// A modified table is sent again
+		{
+			let res = client
+				.get(url)
+				.basic_auth(USER, Some(PASS))
+				.header(header::IF_MODIFIED_SINCE, modified.clone())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let last_modified = res.headers().get(header::LAST_MODIFIED);
+			assert!(last_modified.is_some_and(|v| *v != modified));
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 0, "body: {body}");
+		}
+
+		// A table without a change feed is not cached
+		{
+			let res = client
+				.get(format!("http://{addr}/key/other"))
+				.basic_auth(USER, Some(PASS))
+				.header(header::IF_MODIFIED_SINCE, modified.clone())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			assert!(!res.headers().contains_key(header::LAST_MODIFIED));
+			assert!(!res.headers().contains_key(header::CACHE_CONTROL));
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn key_endpoint_scoped_token() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_with_guests().await.unwrap();
+		let url = &format!("http://{addr}/key");
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Seed the tables, and define an access method and a database viewer
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					CREATE allowed:1, other:1;
+					DEFINE ACCESS scoped ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'secret';
+					DEFINE USER viewer ON DATABASE PASSWORD 'viewer' ROLES VIEWER;
+					"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// Sign a token which only allows reading and creating records in one table
+		let claims = json!({
+			"iss": "surrealdb-test",
+			"exp": chrono::Utc::now().timestamp() + 3600,
+			"ns": ns,
+			"db": db,
+			"ac": "scoped",
+			"tables": ["allowed"],
+			"methods": ["GET", "POST"],
+		});
+		let scope = jsonwebtoken::encode(
+			&jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS512),
+			&claims,
+			&jsonwebtoken::EncodingKey::from_secret(b"secret"),
+		)?;
+
+		// The scoped token allows reading the permitted table
+		{
+			let res = client
+				.get(format!("{url}/allowed"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 1, "body: {body}");
+		}
+
+		// The scoped token narrows the tables which can be accessed
+		{
+			let res = client
+				.get(format!("{url}/other"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 403, "body: {}", res.text().await?);
+		}
+
+		// The scoped token narrows the methods which can be used
+		{
+			let res = client
+				.delete(format!("{url}/allowed"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 403, "body: {}", res.text().await?);
+		}
+
+		// The scoped token does not permit moving a record, as that deletes a record
+		{
+			let res = client
+				.post(format!("{url}/allowed/1/move/3"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 403, "body: {}", res.text().await?);
+
+			let res =
+				client.get(format!("{url}/allowed/1")).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 1, "body: {body}");
+		}
+
+		// The scoped token restricts each operation of a batch
+		{
+			let res = client
+				.post(format!("{url}/batch"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.body(r#"[{ "method": "POST", "table": "allowed", "key": 4, "body": {} }]"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			for op in [
+				r#"[{ "method": "DELETE", "table": "allowed", "key": 1 }]"#,
+				r#"[{ "method": "GET", "table": "other" }]"#,
+			] {
+				let res = client
+					.post(format!("{url}/batch"))
+					.basic_auth(USER, Some(PASS))
+					.header("surreal-scope", &scope)
+					.body(op)
+					.send()
+					.await?;
+				assert_eq!(res.status(), 403, "batch: {op}");
+			}
+		}
+
+		// A scoped token without a tables claim still restricts the methods of a batch
+		{
+			let claims = json!({
+				"iss": "surrealdb-test",
+				"exp": chrono::Utc::now().timestamp() + 3600,
+				"ns": ns,
+				"db": db,
+				"ac": "scoped",
+				"methods": ["POST"],
+			});
+			let scope = jsonwebtoken::encode(
+				&jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS512),
+				&claims,
+				&jsonwebtoken::EncodingKey::from_secret(b"secret"),
+			)?;
+
+			let res = client
+				.post(format!("{url}/batch"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", &scope)
+				.body(r#"[{ "method": "POST", "table": "other", "key": 2, "body": {} }]"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			for method in ["DELETE", "PUT", "PATCH"] {
+				let res = client
+					.post(format!("{url}/batch"))
+					.basic_auth(USER, Some(PASS))
+					.header("surreal-scope", &scope)
+					.body(format!(
+						r#"[{{ "method": "{method}", "table": "other", "body": {{}} }}]"#
+					))
+					.send()
+					.await?;
+				assert_eq!(res.status(), 403, "method: {method}");
+			}
+
+			let res =
+				client.get(format!("{url}/other")).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 2, "body: {body}");
+		}
+
+		// The scoped token does not exceed the rights of the session
+		{
+			let res = client
+				.post(format!("{url}/allowed/2"))
+				.basic_auth("viewer", Some("viewer"))
+				.header(AUTH_NS, &ns)
+				.header(AUTH_DB, &db)
+				.header("surreal-scope", &scope)
+				.body(r#"{ "name": "record" }"#)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["status"], "ERR", "body: {body}");
+
+			let res =
+				client.get(format!("{url}/allowed/2")).basic_auth(USER, Some(PASS)).send().await?;
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?).unwrap();
+			assert_eq!(body[0]["result"].as_array().unwrap().len(), 0, "body: {body}");
+		}
+
+		// The scoped token can not be used with another database
+		{
+			let res = client
+				.get(format!("{url}/allowed"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-db", Ulid::new().to_string())
+				.header("surreal-scope", &scope)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 403, "body: {}", res.text().await?);
+		}
+
+		// An invalid scoped token is rejected
+		{
+			let res = client
+				.get(format!("{url}/allowed"))
+				.basic_auth(USER, Some(PASS))
+				.header("surreal-scope", "invalid")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 401, "body: {}", res.text().await?);
+		}
+
+		Ok(())
+	}
+
 	#[test(tokio::test)]
 	async fn signup_mal() -> Result<(), Box<dyn std::error::Error>> {
 		let (addr, _server) = common::start_server_with_defaults().await.unwrap();
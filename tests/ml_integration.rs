@@ -287,4 +287,212 @@ mod ml_integration {
 		}
 		Ok(())
 	}
+
+	#[test(tokio::test)]
+	async fn batched_compute() -> Result<(), Box<dyn std::error::Error>> {
+		let _lock = LockHandle::acquire_lock();
+		let (addr, _server) = common::start_server_with_defaults().await.unwrap();
+
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+
+		upload_file(&addr, &ns, &db).await?;
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// perform a batched computation over many rows of raw and buffered inputs
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.body(
+					r#"
+					ml::Prediction<0.0.1>([[1.0, 1.0], [1.0, 1.0]]);
+					ml::Prediction<0.0.1>([{squarefoot: 500.0, num_floors: 1.0}, [1.0, 1.0]]);
+					"#,
+				)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			let body = res.text().await?;
+			let deserialized_data: serde_json::Value = serde_json::from_str(&body)?;
+			assert_eq!(deserialized_data[0]["result"][0][0], 0.9998061656951904);
+			assert_eq!(deserialized_data[0]["result"][1][0], 0.9998061656951904);
+			assert_eq!(deserialized_data[1]["result"][0][0], 177206.21875);
+			assert_eq!(deserialized_data[1]["result"][1][0], 0.9998061656951904);
+		}
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn batched_compute_matches_rows() -> Result<(), Box<dyn std::error::Error>> {
+		let _lock = LockHandle::acquire_lock();
+		let (addr, _server) = common::start_server_with_defaults().await.unwrap();
+
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+
+		upload_file(&addr, &ns, &db).await?;
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// generate many rows of raw inputs, which are stacked, and of mixed inputs, which are not
+		let raw: Vec<String> = (0..50).map(|i| format!("[{i}.0, {}.0]", i % 3)).collect();
+		let mixed: Vec<String> = (0..50)
+			.map(|i| match i % 2 {
+				0 => format!("{{squarefoot: {}.0, num_floors: {}.0}}", 500 + i * 10, 1 + i % 3),
+				_ => format!("[{i}.0, {}.0]", i % 3),
+			})
+			.collect();
+
+		for rows in [raw, mixed] {
+			// compute all of the rows at once, and then each row on its own
+			let mut sql = format!("ml::Prediction<0.0.1>([{}]);", rows.join(", "));
+			for row in &rows {
+				sql.push_str(&format!("ml::Prediction<0.0.1>({row});"));
+			}
+			// the batched computation matches the computation of each row
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.body(sql)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			let body = res.text().await?;
+			let deserialized_data: serde_json::Value = serde_json::from_str(&body)?;
+			let batched = deserialized_data[0]["result"].as_array().unwrap();
+			assert_eq!(batched.len(), rows.len());
+			for (i, row) in rows.iter().enumerate() {
+				assert_eq!(batched[i], deserialized_data[i + 1]["result"], "row: {row}");
+			}
+		}
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn upload_model_deduplicated() -> Result<(), Box<dyn std::error::Error>> {
+		let _lock = LockHandle::acquire_lock();
+		let store = std::env::temp_dir().join(format!("surrealdb-ml-{}", Ulid::new()));
+		let (addr, _server) = common::start_server(common::StartServerArguments {
+			vars: Some(std::collections::HashMap::from([
+				("SURREAL_OBJECT_STORE".to_string(), format!("file://{}", store.display())),
+				// Keep recently written files, which may not be referenced yet
+				("SURREAL_ML_GC_INTERVAL".to_string(), "1s".to_string()),
+				("SURREAL_ML_OBJECT_GC_AGE".to_string(), "2".to_string()),
+			])),
+			..Default::default()
+		})
+		.await
+		.unwrap();
+
+		let ns = Ulid::new().to_string();
+		let db1 = Ulid::new().to_string();
+		let db2 = Ulid::new().to_string();
+
+		// Upload identical model bytes under two definitions
+		upload_file(&addr, &ns, &db1).await?;
+		upload_file(&addr, &ns, &db2).await?;
+		let objects = store.join("ml").join("objects");
+		assert_eq!(std::fs::read_dir(&objects)?.count(), 1);
+
+		// Prepare HTTP client
+		let mut headers = reqwest::header::HeaderMap::new();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// Removing one definition keeps the file for the other
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.header("surreal-db", &db1)
+				.body(r#"REMOVE MODEL ml::Prediction<0.0.1>;"#)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			tokio::time::sleep(Duration::from_secs(3)).await;
+			assert_eq!(std::fs::read_dir(&objects)?.count(), 1);
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.header("surreal-db", &db2)
+				.body(r#"ml::Prediction<0.0.1>([1.0, 1.0]);"#)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			let body = res.text().await?;
+			let deserialized_data: Vec<Data> = serde_json::from_str(&body)?;
+			assert_eq!(deserialized_data[0].result[0], 0.9998061656951904);
+		}
+
+		// Removing the last definition in a cancelled transaction keeps the file
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.header("surreal-db", &db2)
+				.body(r#"BEGIN; REMOVE MODEL ml::Prediction<0.0.1>; CANCEL;"#)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			tokio::time::sleep(Duration::from_secs(3)).await;
+			assert_eq!(std::fs::read_dir(&objects)?.count(), 1);
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.header("surreal-db", &db2)
+				.body(r#"ml::Prediction<0.0.1>([1.0, 1.0]);"#)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			let body = res.text().await?;
+			let deserialized_data: Vec<Data> = serde_json::from_str(&body)?;
+			assert_eq!(deserialized_data[0].result[0], 0.9998061656951904);
+		}
+
+		// Removing the last definition lets the file be garbage collected
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(common::USER, Some(common::PASS))
+				.header("surreal-db", &db2)
+				.body(r#"REMOVE MODEL ml::Prediction<0.0.1>;"#)
+				.send()
+				.await?;
+			assert!(res.status().is_success(), "body: {}", res.text().await?);
+			let mut count = std::fs::read_dir(&objects)?.count();
+			for _ in 0..100 {
+				if count == 0 {
+					break;
+				}
+				tokio::time::sleep(Duration::from_millis(100)).await;
+				count = std::fs::read_dir(&objects)?.count();
+			}
+			assert_eq!(count, 0);
+		}
+
+		std::fs::remove_dir_all(&store)?;
+		Ok(())
+	}
 }